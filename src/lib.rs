@@ -12,6 +12,38 @@ const CURVE_ANGLE_TOERANCE_EPSILON: f64 = 0.01;
 const M_ANGLE_TOLERANCE: f64 = 0.0;
 const M_CUSP_LIMIT: f64 = 0.0;
 
+/// User-tunable parameters controlling how finely a curve is subdivided.
+///
+/// `distance_tolerance` is a pixel-space flatness budget (divided by `scale`
+/// the same way `PATH_DISTANCE_EPSILON` was before), `angle_tolerance` and
+/// `cusp_limit` are in radians, and `recursion_limit` bounds the subdivision
+/// depth. `simplify_epsilon`, if set, runs `simplify_polyline` over the
+/// output as an opt-in final pass; every `*_with_options` entry point
+/// (cubic, quadratic, and Catmull-Rom) honors it, so one shared
+/// `FlattenOptions` gives consistent simplification across a mixed
+/// glyph/SVG pipeline. `Default` reproduces the previous hardcoded
+/// behavior with simplification disabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlattenOptions {
+    pub distance_tolerance: f64,
+    pub angle_tolerance: f64,
+    pub cusp_limit: f64,
+    pub recursion_limit: u32,
+    pub simplify_epsilon: Option<f64>,
+}
+
+impl Default for FlattenOptions {
+    fn default() -> Self {
+        FlattenOptions {
+            distance_tolerance: PATH_DISTANCE_EPSILON,
+            angle_tolerance: M_ANGLE_TOLERANCE,
+            cusp_limit: M_CUSP_LIMIT,
+            recursion_limit: RECUSION_LIMIT,
+            simplify_epsilon: None,
+        }
+    }
+}
+
 #[inline]
 fn clamp_angle(x: f64) -> f64 {
     if x >= PI {
@@ -28,22 +60,26 @@ pub fn adaptive_bezier_curve(
     end: Vector2,
     scale: f64,
 ) -> Vec<Vector2> {
-    let distance_tolerance = (PATH_DISTANCE_EPSILON / scale).powi(2);
+    adaptive_bezier_curve_with_options(start, c1, c2, end, scale, &FlattenOptions::default())
+}
+
+pub fn adaptive_bezier_curve_with_options(
+    start: Vector2,
+    c1: Vector2,
+    c2: Vector2,
+    end: Vector2,
+    scale: f64,
+    options: &FlattenOptions,
+) -> Vec<Vector2> {
     let mut sample_points = Vec::new();
-    sample_points.push(start);
-    adaptive_bezier_curve_impl(
-        start,
-        c1,
-        c2,
-        end,
-        &mut sample_points,
-        distance_tolerance,
-        0,
-    );
-    sample_points.push(end);
+    adaptive_bezier_curve_into_with_options(start, c1, c2, end, scale, options, &mut sample_points);
     sample_points
 }
 
+// The four control points are inherent to cubic subdivision's recursive
+// signature; bundling them into a struct would just move the same argument
+// count into a literal at every call site instead of reducing it.
+#[allow(clippy::too_many_arguments)]
 pub fn adaptive_bezier_curve_impl(
     p1: Vector2,
     p2: Vector2,
@@ -52,8 +88,9 @@ pub fn adaptive_bezier_curve_impl(
     points: &mut Vec<Vector2>,
     distance_tolerance: f64,
     level: u32,
+    options: &FlattenOptions,
 ) {
-    if level > RECUSION_LIMIT {
+    if level > options.recursion_limit {
         return;
     }
     let p12 = (p1 + p2) / 2.0;
@@ -73,7 +110,7 @@ pub fn adaptive_bezier_curve_impl(
             if (d2 + d3).powi(2) <= distance_tolerance * d.norm_squared() {
                 // If the curvature doesn't exceed the distanceTolerance value
                 // we tend to finish subdivisions
-                if M_ANGLE_TOLERANCE < CURVE_ANGLE_TOERANCE_EPSILON {
+                if options.angle_tolerance < CURVE_ANGLE_TOERANCE_EPSILON {
                     points.push(p1234);
                     return;
                 }
@@ -82,17 +119,17 @@ pub fn adaptive_bezier_curve_impl(
                 let a23 = (p3.y - p2.y).atan2(p3.x - p2.x);
                 let da1 = clamp_angle((a23 - (p2.y - p1.y).atan2(p2.x - p1.x)).abs());
                 let da2 = clamp_angle(((p4.y - p3.y).atan2(p4.x - p3.x) - a23).abs());
-                if da1 + da2 < M_ANGLE_TOLERANCE {
+                if da1 + da2 < options.angle_tolerance {
                     // Finally we can stop the recursion
                     points.push(p1234);
                     return;
                 }
-                if M_CUSP_LIMIT != 0.0 {
-                    if da1 > M_CUSP_LIMIT {
+                if options.cusp_limit > 0.0 {
+                    if da1 > options.cusp_limit {
                         points.push(p2);
                         return;
                     }
-                    if da2 > M_CUSP_LIMIT {
+                    if da2 > options.cusp_limit {
                         points.push(p3);
                         return;
                     }
@@ -101,7 +138,7 @@ pub fn adaptive_bezier_curve_impl(
         } else if d2 > FLOAT_EPSILON {
             // P_1, P_3, P_4 are collinear, P_2 is considerable
             if d2 * d2 <= distance_tolerance * d.norm_squared() {
-                if M_ANGLE_TOLERANCE < CURVE_ANGLE_TOERANCE_EPSILON {
+                if options.angle_tolerance < CURVE_ANGLE_TOERANCE_EPSILON {
                     points.push(p1234);
                     return;
                 }
@@ -109,12 +146,12 @@ pub fn adaptive_bezier_curve_impl(
                 let da1 = clamp_angle(
                     ((p3.y - p2.y).atan2(p3.x - p2.x) - (p2.y - p1.y).atan2(p2.x - p1.x)).abs(),
                 );
-                if da1 < M_ANGLE_TOLERANCE {
+                if da1 < options.angle_tolerance {
                     points.push(p2);
                     points.push(p3);
                     return;
                 }
-                if M_CUSP_LIMIT != 0.0 && da1 > M_CUSP_LIMIT {
+                if options.cusp_limit > 0.0 && da1 > options.cusp_limit {
                     points.push(p2);
                     return;
                 }
@@ -122,7 +159,7 @@ pub fn adaptive_bezier_curve_impl(
         } else if d3 > FLOAT_EPSILON {
             // P_1, P_2, P_4 are collinear, P_3 is considerable
             if d3 * d3 <= distance_tolerance * d.norm_squared() {
-                if M_ANGLE_TOLERANCE < CURVE_ANGLE_TOERANCE_EPSILON {
+                if options.angle_tolerance < CURVE_ANGLE_TOERANCE_EPSILON {
                     points.push(p1234);
                     return;
                 }
@@ -130,12 +167,12 @@ pub fn adaptive_bezier_curve_impl(
                 let da1 = clamp_angle(
                     ((p4.y - p3.y).atan2(p4.x - p3.x) - (p3.y - p2.y).atan2(p3.x - p2.x)).abs(),
                 );
-                if da1 < M_ANGLE_TOLERANCE {
+                if da1 < options.angle_tolerance {
                     points.push(p2);
                     points.push(p3);
                     return;
                 }
-                if M_CUSP_LIMIT != 0.0 && da1 > M_CUSP_LIMIT {
+                if options.cusp_limit > 0.0 && da1 > options.cusp_limit {
                     points.push(p3);
                     return;
                 }
@@ -150,13 +187,397 @@ pub fn adaptive_bezier_curve_impl(
         }
     }
     // Continue subdivision
-    adaptive_bezier_curve_impl(p1, p12, p123, p1234, points, distance_tolerance, level + 1);
-    adaptive_bezier_curve_impl(p1234, p234, p34, p4, points, distance_tolerance, level + 1);
+    adaptive_bezier_curve_impl(
+        p1,
+        p12,
+        p123,
+        p1234,
+        points,
+        distance_tolerance,
+        level + 1,
+        options,
+    );
+    adaptive_bezier_curve_impl(
+        p1234,
+        p234,
+        p34,
+        p4,
+        points,
+        distance_tolerance,
+        level + 1,
+        options,
+    );
+}
+
+/// Flattens into a caller-owned buffer instead of allocating a new `Vec`.
+///
+/// `out` is cleared before the curve is written, so it can be reused across
+/// many calls without shrinking its capacity.
+pub fn adaptive_bezier_curve_into(
+    start: Vector2,
+    c1: Vector2,
+    c2: Vector2,
+    end: Vector2,
+    scale: f64,
+    out: &mut Vec<Vector2>,
+) {
+    adaptive_bezier_curve_into_with_options(
+        start,
+        c1,
+        c2,
+        end,
+        scale,
+        &FlattenOptions::default(),
+        out,
+    )
+}
+
+pub fn adaptive_bezier_curve_into_with_options(
+    start: Vector2,
+    c1: Vector2,
+    c2: Vector2,
+    end: Vector2,
+    scale: f64,
+    options: &FlattenOptions,
+    out: &mut Vec<Vector2>,
+) {
+    let distance_tolerance = (options.distance_tolerance / scale).powi(2);
+    out.clear();
+    out.push(start);
+    adaptive_bezier_curve_impl(start, c1, c2, end, out, distance_tolerance, 0, options);
+    out.push(end);
+    if let Some(epsilon) = options.simplify_epsilon {
+        *out = simplify_polyline(out, epsilon);
+    }
+}
+
+/// A reusable scratch buffer for flattening many curves without
+/// reallocating a `Vec` per call, e.g. when walking every curve in a glyph
+/// outline or SVG path. When `simplify_epsilon` is set, the simplified
+/// result is a newly allocated `Vec` that replaces the buffer on each call.
+#[derive(Debug, Default)]
+pub struct CurveBuffers {
+    points: Vec<Vector2>,
+}
+
+impl CurveBuffers {
+    pub fn new() -> Self {
+        CurveBuffers { points: Vec::new() }
+    }
+
+    /// Flattens the curve into the owned buffer and returns the result.
+    pub fn flatten(
+        &mut self,
+        start: Vector2,
+        c1: Vector2,
+        c2: Vector2,
+        end: Vector2,
+        scale: f64,
+        options: &FlattenOptions,
+    ) -> &[Vector2] {
+        adaptive_bezier_curve_into_with_options(
+            start,
+            c1,
+            c2,
+            end,
+            scale,
+            options,
+            &mut self.points,
+        );
+        &self.points
+    }
+}
+
+pub fn adaptive_quadratic_bezier_curve(
+    start: Vector2,
+    control: Vector2,
+    end: Vector2,
+    scale: f64,
+) -> Vec<Vector2> {
+    adaptive_quadratic_bezier_curve_with_options(
+        start,
+        control,
+        end,
+        scale,
+        &FlattenOptions::default(),
+    )
+}
+
+pub fn adaptive_quadratic_bezier_curve_with_options(
+    start: Vector2,
+    control: Vector2,
+    end: Vector2,
+    scale: f64,
+    options: &FlattenOptions,
+) -> Vec<Vector2> {
+    let distance_tolerance = (options.distance_tolerance / scale).powi(2);
+    let mut sample_points = Vec::new();
+    sample_points.push(start);
+    adaptive_quadratic_bezier_curve_impl(
+        start,
+        control,
+        end,
+        &mut sample_points,
+        distance_tolerance,
+        0,
+        options,
+    );
+    sample_points.push(end);
+    if let Some(epsilon) = options.simplify_epsilon {
+        sample_points = simplify_polyline(&sample_points, epsilon);
+    }
+    sample_points
+}
+
+pub fn adaptive_quadratic_bezier_curve_impl(
+    p1: Vector2,
+    p2: Vector2,
+    p3: Vector2,
+    points: &mut Vec<Vector2>,
+    distance_tolerance: f64,
+    level: u32,
+    options: &FlattenOptions,
+) {
+    if level > options.recursion_limit {
+        return;
+    }
+    let p12 = (p1 + p2) / 2.0;
+    let p23 = (p2 + p3) / 2.0;
+    let p123 = (p12 + p23) / 2.0;
+    if level > 0 {
+        let d = p3 - p1;
+        let dist = (p2 - p3).perp(&d).abs();
+        if dist > FLOAT_EPSILON {
+            if dist * dist <= distance_tolerance * d.norm_squared() {
+                if options.angle_tolerance < CURVE_ANGLE_TOERANCE_EPSILON {
+                    points.push(p123);
+                    return;
+                }
+                // Angle condition
+                let da = clamp_angle(
+                    ((p3.y - p2.y).atan2(p3.x - p2.x) - (p2.y - p1.y).atan2(p2.x - p1.x)).abs(),
+                );
+                if da < options.angle_tolerance {
+                    points.push(p123);
+                    return;
+                }
+            }
+        } else {
+            // Collinear case
+            let d = p123 - (p1 + p3) / 2.0;
+            if d.norm_squared() < distance_tolerance {
+                points.push(p123);
+                return;
+            }
+        }
+    }
+    // Continue subdivision
+    adaptive_quadratic_bezier_curve_impl(
+        p1,
+        p12,
+        p123,
+        points,
+        distance_tolerance,
+        level + 1,
+        options,
+    );
+    adaptive_quadratic_bezier_curve_impl(
+        p123,
+        p23,
+        p3,
+        points,
+        distance_tolerance,
+        level + 1,
+        options,
+    );
+}
+
+#[inline]
+fn perpendicular_distance(point: Vector2, line_start: Vector2, line_end: Vector2) -> f64 {
+    let d = line_end - line_start;
+    let length = d.norm();
+    if length < FLOAT_EPSILON {
+        return (point - line_start).norm();
+    }
+    (point - line_start).perp(&d).abs() / length
+}
+
+/// Reduces a flattened polyline to its minimal set of vertices using the
+/// Ramer-Douglas-Peucker algorithm: the span is split at its point farthest
+/// from the chord between the span's first and last points while that
+/// distance exceeds `epsilon`; otherwise only the span's endpoints are kept.
+/// This is an opt-in final step for
+/// callers who want a minimal-vertex polyline rather than the denser output
+/// of adaptive subdivision.
+pub fn simplify_polyline(points: &[Vector2], epsilon: f64) -> Vec<Vector2> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let first = points[0];
+    let last = points[points.len() - 1];
+    let (farthest_index, farthest_distance) = points[1..points.len() - 1]
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| (i + 1, perpendicular_distance(p, first, last)))
+        .fold((0, 0.0), |farthest, candidate| {
+            if candidate.1 > farthest.1 {
+                candidate
+            } else {
+                farthest
+            }
+        });
+    if farthest_distance > epsilon {
+        let mut head = simplify_polyline(&points[..=farthest_index], epsilon);
+        let tail = simplify_polyline(&points[farthest_index..], epsilon);
+        head.pop(); // drop the vertex duplicated at the split point
+        head.extend(tail);
+        head
+    } else {
+        vec![first, last]
+    }
+}
+
+/// Number of chords needed to keep a circular arc of `radius` within
+/// `tolerance` of its true path, derived from the sagitta of each chord.
+fn arc_segment_count(radius: f64, theta_range: f64, tolerance: f64) -> u32 {
+    if tolerance >= radius {
+        return 1;
+    }
+    let max_angle_per_segment = 2.0 * (1.0 - tolerance / radius).acos();
+    (theta_range.abs() / max_angle_per_segment).ceil().max(1.0) as u32
+}
+
+/// Flattens a circular arc to within the same pixel-level tolerance used for
+/// Bezier curves (`PATH_DISTANCE_EPSILON / scale`). `theta_range` may be
+/// negative for a clockwise arc.
+pub fn adaptive_arc(
+    center: Vector2,
+    radius: f64,
+    theta_start: f64,
+    theta_range: f64,
+    scale: f64,
+) -> Vec<Vector2> {
+    let tolerance = PATH_DISTANCE_EPSILON / scale;
+    let segment_count = arc_segment_count(radius, theta_range, tolerance);
+    (0..=segment_count)
+        .map(|i| {
+            let theta = theta_start + theta_range * (i as f64) / (segment_count as f64);
+            center + Vector2::new(theta.cos(), theta.sin()) * radius
+        })
+        .collect()
+}
+
+/// Converts an SVG arc-to (`A`) command's endpoint parameterization into the
+/// center/start-angle/range form `adaptive_arc` expects. Only circular arcs
+/// (equal radii, no axis rotation) are supported.
+pub fn arc_endpoint_to_center(
+    start: Vector2,
+    end: Vector2,
+    radius: f64,
+    large_arc: bool,
+    sweep: bool,
+) -> (Vector2, f64, f64) {
+    let mid = (start + end) / 2.0;
+    let chord = end - start;
+    let chord_len = chord.norm();
+    if chord_len < FLOAT_EPSILON {
+        // SVG treats a coincident-endpoint arc as "omit the segment"; return
+        // a degenerate zero-sweep arc centered at the shared point instead
+        // of dividing by zero and propagating NaN to callers.
+        return (start, 0.0, 0.0);
+    }
+    let half_chord = chord_len / 2.0;
+    let h = (radius * radius - half_chord * half_chord).max(0.0).sqrt();
+    let perp = Vector2::new(-chord.y, chord.x) / chord_len;
+    let offset = if large_arc == sweep { -h } else { h };
+    let center = mid + perp * offset;
+    let theta_start = (start - center).y.atan2((start - center).x);
+    let theta_end = (end - center).y.atan2((end - center).x);
+    let mut theta_range = theta_end - theta_start;
+    if sweep && theta_range < 0.0 {
+        theta_range += TAU;
+    } else if !sweep && theta_range > 0.0 {
+        theta_range -= TAU;
+    }
+    (center, theta_start, theta_range)
+}
+
+/// Duplicates the first and last waypoint so every interior segment has the
+/// neighbors a Catmull-Rom spline needs, including the first and last ones.
+fn padded_catmull_rom_points(points: &[Vector2]) -> Vec<Vector2> {
+    let mut padded = Vec::with_capacity(points.len() + 2);
+    padded.push(points[0]);
+    padded.extend_from_slice(points);
+    padded.push(points[points.len() - 1]);
+    padded
+}
+
+pub fn adaptive_catmull_rom(points: &[Vector2], scale: f64) -> Vec<Vector2> {
+    adaptive_catmull_rom_with_options(points, scale, &FlattenOptions::default())
+}
+
+/// Flattens a Catmull-Rom spline through `points`, converting each interior
+/// segment `P0..P3` to the equivalent cubic Bezier (`B1 = P1`,
+/// `B2 = P1 + (P2 - P0) / 6`, `B3 = P2 - (P3 - P1) / 6`, `B4 = P2`) and
+/// delegating to the existing cubic subdivider, so the result uses the same
+/// tolerance semantics as `adaptive_bezier_curve`.
+pub fn adaptive_catmull_rom_with_options(
+    points: &[Vector2],
+    scale: f64,
+    options: &FlattenOptions,
+) -> Vec<Vector2> {
+    if points.len() < 2 {
+        return points.to_vec();
+    }
+    let distance_tolerance = (options.distance_tolerance / scale).powi(2);
+    let padded = padded_catmull_rom_points(points);
+    let mut sample_points = Vec::new();
+    sample_points.push(points[0]);
+    for segment in padded.windows(4) {
+        let (p0, p1, p2, p3) = (segment[0], segment[1], segment[2], segment[3]);
+        let b1 = p1;
+        let b2 = p1 + (p2 - p0) / 6.0;
+        let b3 = p2 - (p3 - p1) / 6.0;
+        let b4 = p2;
+        adaptive_bezier_curve_impl(
+            b1,
+            b2,
+            b3,
+            b4,
+            &mut sample_points,
+            distance_tolerance,
+            0,
+            options,
+        );
+        sample_points.push(b4);
+    }
+    if let Some(epsilon) = options.simplify_epsilon {
+        sample_points = simplify_polyline(&sample_points, epsilon);
+    }
+    sample_points
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{adaptive_bezier_curve, Vector2, FLOAT_EPSILON};
+    use super::{
+        adaptive_arc, adaptive_bezier_curve, adaptive_bezier_curve_impl,
+        adaptive_bezier_curve_with_options, adaptive_catmull_rom,
+        adaptive_catmull_rom_with_options, adaptive_quadratic_bezier_curve,
+        adaptive_quadratic_bezier_curve_with_options, arc_endpoint_to_center, simplify_polyline,
+        CurveBuffers, FlattenOptions, Vector2, FLOAT_EPSILON,
+    };
+    use std::f64::consts::PI;
+
+    /// The cubic control polygon shared by the options-API tests below.
+    fn sample_cubic() -> (Vector2, Vector2, Vector2, Vector2, f64) {
+        (
+            Vector2::new(20.0, 20.0),
+            Vector2::new(100.0, 159.0),
+            Vector2::new(50.0, 200.0),
+            Vector2::new(200.0, 20.0),
+            2.0,
+        )
+    }
 
     #[test]
     fn simple() {
@@ -195,4 +616,238 @@ mod tests {
             assert_eq!((output[i].y - answer[i].y).abs() < FLOAT_EPSILON, true);
         }
     }
+
+    #[test]
+    fn default_options_match_plain_curve() {
+        let (start, c1, c2, end, scale) = sample_cubic();
+        let plain = adaptive_bezier_curve(start, c1, c2, end, scale);
+        let with_defaults = adaptive_bezier_curve_with_options(
+            start,
+            c1,
+            c2,
+            end,
+            scale,
+            &FlattenOptions::default(),
+        );
+        assert_eq!(plain, with_defaults);
+    }
+
+    #[test]
+    fn simplify_epsilon_reduces_the_flattened_output() {
+        let (start, c1, c2, end, scale) = sample_cubic();
+        let dense = adaptive_bezier_curve(start, c1, c2, end, scale);
+        let simplified = adaptive_bezier_curve_with_options(
+            start,
+            c1,
+            c2,
+            end,
+            scale,
+            &FlattenOptions {
+                simplify_epsilon: Some(5.0),
+                ..FlattenOptions::default()
+            },
+        );
+        assert!(simplified.len() < dense.len());
+        assert_eq!(*simplified.first().unwrap(), start);
+        assert_eq!(*simplified.last().unwrap(), end);
+    }
+
+    #[test]
+    fn angle_tolerance_keeps_subdividing_past_the_flatness_test() {
+        let (start, c1, c2, end, scale) = sample_cubic();
+        let loose = adaptive_bezier_curve_with_options(
+            start,
+            c1,
+            c2,
+            end,
+            scale,
+            &FlattenOptions {
+                angle_tolerance: 0.2,
+                ..FlattenOptions::default()
+            },
+        );
+        let tight = adaptive_bezier_curve(start, c1, c2, end, scale);
+        assert!(loose.len() >= tight.len());
+    }
+
+    #[test]
+    fn cusp_limit_emits_the_sharp_control_point() {
+        // Control polygon is almost flat (tiny perpendicular deviation from
+        // p1-p4) but turns by ~90 degrees at p2/p3, the case cusp_limit
+        // exists to catch: the flatness test alone would pass and lose the
+        // corner.
+        let p1 = Vector2::new(0.0, 0.0);
+        let p2 = Vector2::new(5.0, 0.001);
+        let p3 = Vector2::new(5.0, -0.001);
+        let p4 = Vector2::new(10.0, 0.0);
+        let options = FlattenOptions {
+            angle_tolerance: 0.2,
+            cusp_limit: 1.0,
+            ..FlattenOptions::default()
+        };
+        let mut points = Vec::new();
+        adaptive_bezier_curve_impl(p1, p2, p3, p4, &mut points, 1.0, 1, &options);
+        assert_eq!(points, vec![p2]);
+    }
+
+    #[test]
+    fn negative_cusp_limit_is_treated_as_disabled() {
+        let p1 = Vector2::new(0.0, 0.0);
+        let p2 = Vector2::new(5.0, 0.001);
+        let p3 = Vector2::new(5.0, -0.001);
+        let p4 = Vector2::new(10.0, 0.0);
+        let options = FlattenOptions {
+            angle_tolerance: 0.2,
+            cusp_limit: -1.0,
+            ..FlattenOptions::default()
+        };
+        let mut points = Vec::new();
+        adaptive_bezier_curve_impl(p1, p2, p3, p4, &mut points, 1.0, 1, &options);
+        assert_ne!(points, vec![p2]);
+    }
+
+    #[test]
+    fn quadratic_endpoints_are_exact() {
+        let start = Vector2::new(20.0, 20.0);
+        let control = Vector2::new(100.0, 159.0);
+        let end = Vector2::new(200.0, 20.0);
+        let scale = 2.0;
+        let output = adaptive_quadratic_bezier_curve(start, control, end, scale);
+        assert_eq!(*output.first().unwrap(), start);
+        assert_eq!(*output.last().unwrap(), end);
+        assert!(output.len() >= 2);
+    }
+
+    #[test]
+    fn quadratic_simplify_epsilon_reduces_the_flattened_output() {
+        let start = Vector2::new(20.0, 20.0);
+        let control = Vector2::new(100.0, 159.0);
+        let end = Vector2::new(200.0, 20.0);
+        let scale = 2.0;
+        let dense = adaptive_quadratic_bezier_curve(start, control, end, scale);
+        let simplified = adaptive_quadratic_bezier_curve_with_options(
+            start,
+            control,
+            end,
+            scale,
+            &FlattenOptions {
+                simplify_epsilon: Some(1000.0),
+                ..FlattenOptions::default()
+            },
+        );
+        assert!(simplified.len() < dense.len());
+        assert_eq!(*simplified.first().unwrap(), start);
+        assert_eq!(*simplified.last().unwrap(), end);
+    }
+
+    #[test]
+    fn curve_buffers_match_allocating_variant() {
+        let (start, c1, c2, end, scale) = sample_cubic();
+        let expected = adaptive_bezier_curve(start, c1, c2, end, scale);
+        let mut buffers = CurveBuffers::new();
+        let reused = buffers.flatten(start, c1, c2, end, scale, &FlattenOptions::default());
+        assert_eq!(reused, expected.as_slice());
+        // Reusing the buffer for a second curve must not leak the first one's points.
+        let second = buffers.flatten(end, c2, c1, start, scale, &FlattenOptions::default());
+        assert_eq!(second.first().unwrap(), &end);
+        assert_eq!(second.last().unwrap(), &start);
+    }
+
+    #[test]
+    fn simplify_polyline_drops_collinear_points() {
+        let points = vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 0.0),
+            Vector2::new(2.0, 0.0),
+            Vector2::new(3.0, 0.0),
+        ];
+        let simplified = simplify_polyline(&points, 0.01);
+        assert_eq!(simplified, vec![points[0], points[3]]);
+    }
+
+    #[test]
+    fn simplify_polyline_keeps_points_outside_epsilon() {
+        let points = vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 5.0),
+            Vector2::new(2.0, 0.0),
+        ];
+        let simplified = simplify_polyline(&points, 0.01);
+        assert_eq!(simplified, points);
+    }
+
+    #[test]
+    fn adaptive_arc_endpoints_lie_on_the_circle() {
+        let center = Vector2::new(10.0, 10.0);
+        let radius = 50.0;
+        let output = adaptive_arc(center, radius, 0.0, PI / 2.0, 2.0);
+        assert!(output.len() >= 2);
+        for point in &output {
+            assert!(((point - center).norm() - radius).abs() < 1e-9);
+        }
+        assert!((output.first().unwrap() - (center + Vector2::new(radius, 0.0))).norm() < 1e-9);
+        assert!((output.last().unwrap() - (center + Vector2::new(0.0, radius))).norm() < 1e-9);
+    }
+
+    #[test]
+    fn arc_endpoint_to_center_recovers_known_circle() {
+        let start = Vector2::new(1.0, 0.0);
+        let end = Vector2::new(0.0, 1.0);
+        let (center, theta_start, theta_range) =
+            arc_endpoint_to_center(start, end, 1.0, false, true);
+        assert!(center.norm() < 1e-9);
+        assert!(theta_start.abs() < 1e-9);
+        assert!((theta_range - PI / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn arc_endpoint_to_center_handles_coincident_endpoints() {
+        let point = Vector2::new(3.0, 4.0);
+        let (center, theta_start, theta_range) =
+            arc_endpoint_to_center(point, point, 1.0, false, true);
+        assert_eq!(center, point);
+        assert_eq!(theta_start, 0.0);
+        assert_eq!(theta_range, 0.0);
+    }
+
+    #[test]
+    fn catmull_rom_passes_through_every_waypoint() {
+        let waypoints = vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(50.0, 100.0),
+            Vector2::new(100.0, 0.0),
+            Vector2::new(150.0, 100.0),
+        ];
+        let output = adaptive_catmull_rom(&waypoints, 2.0);
+        for waypoint in &waypoints {
+            assert!(output
+                .iter()
+                .any(|point| (point - waypoint).norm() < FLOAT_EPSILON));
+        }
+        assert_eq!(*output.first().unwrap(), waypoints[0]);
+        assert_eq!(*output.last().unwrap(), *waypoints.last().unwrap());
+    }
+
+    #[test]
+    fn catmull_rom_simplify_epsilon_reduces_the_flattened_output() {
+        let waypoints = vec![
+            Vector2::new(0.0, 0.0),
+            Vector2::new(50.0, 100.0),
+            Vector2::new(100.0, 0.0),
+            Vector2::new(150.0, 100.0),
+        ];
+        let scale = 2.0;
+        let dense = adaptive_catmull_rom(&waypoints, scale);
+        let simplified = adaptive_catmull_rom_with_options(
+            &waypoints,
+            scale,
+            &FlattenOptions {
+                simplify_epsilon: Some(1000.0),
+                ..FlattenOptions::default()
+            },
+        );
+        assert!(simplified.len() < dense.len());
+        assert_eq!(*simplified.first().unwrap(), waypoints[0]);
+        assert_eq!(*simplified.last().unwrap(), *waypoints.last().unwrap());
+    }
 }